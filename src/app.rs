@@ -1,19 +1,26 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::fl;
 use cosmic::app::{Command, Core, Message};
+use cosmic::dialog::file_chooser;
 use cosmic::iced::{self, event, Alignment, Event, Length};
 use cosmic::iced_runtime::window;
-use cosmic::widget::{self, menu};
+use cosmic::widget::{self, menu, nav_bar};
 use cosmic::{cosmic_theme, style, theme, Application, ApplicationExt, Element};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 const REPOSITORY: &str = "https://github.com/edfloreshz/cosmic-app-template";
 const SVG_DIR: &str = "";
-const GRID_ITEM_WIDTH: usize = 256;
+const DEFAULT_THUMBNAIL_SIZE: usize = 96;
+const MIN_THUMBNAIL_SIZE: usize = 32;
+const MAX_THUMBNAIL_SIZE: usize = 512;
+/// Resolutions (in pixels) offered by the PNG export dropdown.
+const EXPORT_RESOLUTIONS: &[u32] = &[32, 64, 128, 256, 512];
+/// Display labels for [`EXPORT_RESOLUTIONS`], kept in sync with it.
+const EXPORT_RESOLUTION_LABELS: &[&str] = &["32px", "64px", "128px", "256px", "512px"];
 
 /// This is the struct that represents your application.
 /// It is used to define the data that will be used by your application.
@@ -24,7 +31,29 @@ pub struct Svger {
     context_page: ContextPage,
     /// Key bindings for the application's menu bar.
     key_binds: HashMap<menu::KeyBind, MenuAction>,
+    /// Navigation sidebar populated with the subdirectories of the opened folder.
+    nav: nav_bar::Model,
     svg_files: Vec<PathBuf>,
+    /// Case-insensitive filter applied to the grid by file stem.
+    search_query: String,
+    /// Whether the detail drawer shows the rendered icon or its raw source.
+    detail_view_mode: DetailViewMode,
+    /// Metadata for the SVG shown in the detail drawer, parsed on selection.
+    svg_details: Option<SvgDetails>,
+    /// Edge length in pixels of each grid thumbnail, controlled by the zoom slider.
+    thumbnail_size: usize,
+    /// Whether the grid is in multi-select mode for batch export.
+    selection_mode: bool,
+    /// Files currently selected for export.
+    selected: HashSet<PathBuf>,
+    /// Index into [`EXPORT_RESOLUTIONS`] for the chosen export resolution.
+    export_resolution: usize,
+    /// Export progress as `(done, total)` while a batch export is running.
+    export_progress: Option<(usize, usize)>,
+    /// Number of files that failed to render in the running/last export.
+    export_failures: usize,
+    /// Summary of the last finished export as `(succeeded, total)`.
+    export_result: Option<(usize, usize)>,
     grid_rows_count: Option<usize>,
 }
 
@@ -36,26 +65,137 @@ pub enum SvgerMessage {
     LaunchUrl(String),
     ToggleContextPage(ContextPage),
     UpdateGridRowsCount(Option<usize>),
+    OpenFolder,
+    FolderSelected(PathBuf),
+    SearchInput(String),
+    OpenExternally(PathBuf),
+    CopyPath(PathBuf),
+    DeleteFile(PathBuf),
+    ShowDetails(PathBuf),
+    SetDetailViewMode(DetailViewMode),
+    FilesDropped(Vec<PathBuf>),
+    SetThumbnailSize(usize),
+    ToggleSelectionMode,
+    ToggleSelected(PathBuf),
+    SetExportResolution(usize),
+    ExportSelected,
+    ExportDestination(PathBuf),
+    ExportProgress { success: bool, total: usize },
+    ExportDone,
 }
 
 /// Identifies a context page to display in the context drawer.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub enum ContextPage {
     #[default]
     About,
+    Details(PathBuf),
 }
 
 impl ContextPage {
     fn title(&self) -> String {
         match self {
             Self::About => fl!("about"),
+            Self::Details(_) => fl!("details"),
         }
     }
 }
 
+/// How the SVG detail drawer renders the selected file.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DetailViewMode {
+    #[default]
+    Rendered,
+    Raw,
+}
+
+/// Metadata parsed from an SVG document when its detail page is opened.
+#[derive(Clone, Debug, Default)]
+pub struct SvgDetails {
+    source: String,
+    width: Option<String>,
+    height: Option<String>,
+    view_box: Option<String>,
+    path_count: usize,
+    g_count: usize,
+}
+
+impl SvgDetails {
+    /// Parse the SVG at `path`, extracting its raw source and a few structural attributes.
+    fn from_path(path: &Path) -> Self {
+        let source = fs::read_to_string(path).unwrap_or_default();
+
+        Self {
+            width: attribute_value(&source, "width"),
+            height: attribute_value(&source, "height"),
+            view_box: attribute_value(&source, "viewBox"),
+            path_count: count_tag(&source, "path"),
+            g_count: count_tag(&source, "g"),
+            source,
+        }
+    }
+}
+
+/// Extract the first `name=...` attribute value from an SVG document,
+/// tolerating whitespace around `=` and either single or double quotes.
+fn attribute_value(source: &str, name: &str) -> Option<String> {
+    let mut rest = source;
+
+    while let Some(offset) = rest.find(name) {
+        let after = &rest[offset + name.len()..];
+        // The match must be a whole attribute name, not a suffix of another.
+        let is_boundary = rest[..offset]
+            .chars()
+            .last()
+            .map_or(true, |ch| !ch.is_alphanumeric() && ch != '-');
+        let trimmed = after.trim_start();
+
+        if is_boundary {
+            if let Some(value) = trimmed.strip_prefix('=') {
+                let value = value.trim_start();
+                let quote = value.chars().next()?;
+                if quote == '"' || quote == '\'' {
+                    let value = &value[1..];
+                    let end = value.find(quote)?;
+                    return Some(value[..end].to_string());
+                }
+            }
+        }
+
+        rest = after;
+    }
+
+    None
+}
+
+/// Count opening `<name>` tags, matching on tag boundaries so `<g` does not
+/// also match `<glyph>` or `<linearGradient>`.
+fn count_tag(source: &str, name: &str) -> usize {
+    let needle = format!("<{name}");
+    let mut rest = source;
+    let mut count = 0;
+
+    while let Some(offset) = rest.find(&needle) {
+        let after = &rest[offset + needle.len()..];
+        if after
+            .chars()
+            .next()
+            .map_or(true, |ch| ch.is_whitespace() || ch == '>' || ch == '/')
+        {
+            count += 1;
+        }
+        rest = after;
+    }
+
+    count
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MenuAction {
     About,
+    OpenFolder,
+    ToggleSelectionMode,
+    ExportSelected,
 }
 
 impl menu::action::MenuAction for MenuAction {
@@ -64,19 +204,44 @@ impl menu::action::MenuAction for MenuAction {
     fn message(&self) -> Self::Message {
         match self {
             MenuAction::About => SvgerMessage::ToggleContextPage(ContextPage::About),
+            MenuAction::OpenFolder => SvgerMessage::OpenFolder,
+            MenuAction::ToggleSelectionMode => SvgerMessage::ToggleSelectionMode,
+            MenuAction::ExportSelected => SvgerMessage::ExportSelected,
+        }
+    }
+}
+
+/// Actions offered by a grid cell's right-click context menu.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FileAction {
+    OpenExternally(PathBuf),
+    CopyPath(PathBuf),
+    DeleteFile(PathBuf),
+}
+
+impl menu::action::MenuAction for FileAction {
+    type Message = SvgerMessage;
+
+    fn message(&self) -> Self::Message {
+        match self.clone() {
+            FileAction::OpenExternally(path) => SvgerMessage::OpenExternally(path),
+            FileAction::CopyPath(path) => SvgerMessage::CopyPath(path),
+            FileAction::DeleteFile(path) => SvgerMessage::DeleteFile(path),
         }
     }
 }
 
-fn list_svg_files(dir: &str) -> Vec<PathBuf> {
-    let path = Path::new(dir);
+/// Recursively collect every `.svg` file under `dir`.
+fn list_svg_files(dir: &Path) -> Vec<PathBuf> {
     let mut svg_files = Vec::new();
 
-    if path.is_dir() {
-        if let Ok(entries) = fs::read_dir(path) {
+    if dir.is_dir() {
+        if let Ok(entries) = fs::read_dir(dir) {
             for entry in entries.flatten() {
                 let path = entry.path();
-                if path.is_file() && path.extension().map_or(false, |ext| ext == "svg") {
+                if path.is_dir() {
+                    svg_files.extend(list_svg_files(&path));
+                } else if path.extension().map_or(false, |ext| ext == "svg") {
                     svg_files.push(path);
                 }
             }
@@ -86,6 +251,67 @@ fn list_svg_files(dir: &str) -> Vec<PathBuf> {
     svg_files
 }
 
+/// Recursively collect the subdirectories under `dir`, in walk order.
+fn list_subdirs(dir: &Path) -> Vec<PathBuf> {
+    let mut subdirs = Vec::new();
+
+    if dir.is_dir() {
+        if let Ok(entries) = fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    subdirs.push(path.clone());
+                    subdirs.extend(list_subdirs(&path));
+                }
+            }
+        }
+    }
+
+    subdirs
+}
+
+/// Rasterize the SVG at `src` to a `size`×`size` PNG written into `dest_dir`.
+fn render_svg_to_png(src: &Path, dest_dir: &Path, size: u32) -> Result<(), String> {
+    let data = fs::read(src).map_err(|err| err.to_string())?;
+    let tree = usvg::Tree::from_data(&data, &usvg::Options::default())
+        .map_err(|err| err.to_string())?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(size, size)
+        .ok_or_else(|| "invalid export resolution".to_string())?;
+
+    let scale = size as f32 / tree.size().width().max(tree.size().height());
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    let stem = src
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("icon");
+    // Disambiguate files gathered from different subdirectories by the recursive
+    // walk (e.g. `16x16/folder.svg` and `24x24/folder.svg`) so one does not
+    // silently overwrite the other.
+    let parent = src
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .and_then(|name| name.to_str());
+    let base = match parent {
+        Some(parent) => format!("{parent}_{stem}"),
+        None => stem.to_string(),
+    };
+
+    let mut dest = dest_dir.join(format!("{base}.png"));
+    let mut suffix = 1;
+    while dest.exists() {
+        dest = dest_dir.join(format!("{base}-{suffix}.png"));
+        suffix += 1;
+    }
+
+    pixmap.save_png(dest).map_err(|err| err.to_string())
+}
+
 /// Implement the `Application` trait for your application.
 /// This is where you define the behavior of your application.
 ///
@@ -123,7 +349,18 @@ impl Application for Svger {
             core,
             context_page: ContextPage::default(),
             key_binds: HashMap::new(),
-            svg_files: list_svg_files(SVG_DIR),
+            nav: nav_bar::Model::default(),
+            svg_files: list_svg_files(Path::new(SVG_DIR)),
+            search_query: String::new(),
+            detail_view_mode: DetailViewMode::default(),
+            svg_details: None,
+            thumbnail_size: DEFAULT_THUMBNAIL_SIZE,
+            selection_mode: false,
+            selected: HashSet::new(),
+            export_resolution: 3,
+            export_progress: None,
+            export_failures: 0,
+            export_result: None,
             grid_rows_count: None,
         };
 
@@ -134,17 +371,79 @@ impl Application for Svger {
 
     /// Elements to pack at the start of the header bar.
     fn header_start(&self) -> Vec<Element<Self::Message>> {
-        let menu_bar = menu::bar(vec![menu::Tree::with_children(
-            menu::root(fl!("view")),
-            menu::items(
-                &self.key_binds,
-                vec![menu::Item::Button(fl!("about"), MenuAction::About)],
+        let menu_bar = menu::bar(vec![
+            menu::Tree::with_children(
+                menu::root(fl!("file")),
+                menu::items(
+                    &self.key_binds,
+                    vec![
+                        menu::Item::Button(fl!("open-folder"), MenuAction::OpenFolder),
+                        menu::Item::Divider,
+                        menu::Item::Button(fl!("select"), MenuAction::ToggleSelectionMode),
+                        menu::Item::Button(fl!("export-png"), MenuAction::ExportSelected),
+                    ],
+                ),
             ),
-        )]);
+            menu::Tree::with_children(
+                menu::root(fl!("view")),
+                menu::items(
+                    &self.key_binds,
+                    vec![menu::Item::Button(fl!("about"), MenuAction::About)],
+                ),
+            ),
+        ]);
 
         vec![menu_bar.into()]
     }
 
+    /// The export-resolution dropdown, zoom slider, and search field, packed
+    /// at the end of the header bar.
+    fn header_end(&self) -> Vec<Element<Self::Message>> {
+        let zoom = widget::slider(
+            (MIN_THUMBNAIL_SIZE as u16)..=(MAX_THUMBNAIL_SIZE as u16),
+            self.thumbnail_size as u16,
+            |value| SvgerMessage::SetThumbnailSize(value as usize),
+        )
+        .width(Length::Fixed(128.0));
+
+        let resolution = widget::dropdown(
+            EXPORT_RESOLUTION_LABELS,
+            Some(self.export_resolution),
+            SvgerMessage::SetExportResolution,
+        );
+
+        vec![
+            resolution.into(),
+            zoom.into(),
+            widget::search_input(fl!("search-placeholder"), &self.search_query)
+                .on_input(SvgerMessage::SearchInput)
+                .on_clear(SvgerMessage::SearchInput(String::new()))
+                .into(),
+        ]
+    }
+
+    /// Clear the active filter when the COSMIC search affordance is dismissed.
+    fn on_search(&mut self) -> Command<Self::Message> {
+        self.search_query.clear();
+        self.update_grid_rows_count()
+    }
+
+    /// Provide the navigation sidebar model to the COSMIC runtime.
+    fn nav_model(&self) -> Option<&nav_bar::Model> {
+        Some(&self.nav)
+    }
+
+    /// Called when a navigation entry is selected: repopulate the grid from that subtree.
+    fn on_nav_select(&mut self, id: nav_bar::Id) -> Command<Self::Message> {
+        self.nav.activate(id);
+
+        if let Some(dir) = self.nav.data::<PathBuf>(id) {
+            self.svg_files = list_svg_files(dir);
+        }
+
+        self.update_grid_rows_count()
+    }
+
     /// This is the main view of your application, it is the root of your widget tree.
     ///
     /// The `Element` type is used to represent the visual elements of your application,
@@ -160,21 +459,46 @@ impl Application for Svger {
 
         let mut row_count = 0;
 
-        for path in self.svg_files.iter() {
-            svg_grid = svg_grid.push(
-                widget::column()
-                    .push(
-                        widget::svg(widget::svg::Handle::from_path(path))
-                            .width(96)
-                            .height(96),
-                    )
-                    .push(widget::text::caption(
-                        path.file_name().unwrap().to_str().unwrap(),
-                    ))
-                    .spacing(8)
-                    .align_items(Alignment::Center),
+        for path in self.filtered_files() {
+            let cell = widget::column()
+                .push(
+                    widget::svg(widget::svg::Handle::from_path(path))
+                        .width(self.thumbnail_size as u16)
+                        .height(self.thumbnail_size as u16),
+                )
+                .push(widget::text::caption(
+                    path.file_name().unwrap().to_str().unwrap(),
+                ))
+                .spacing(8)
+                .align_items(Alignment::Center);
+
+            let cell = if self.selection_mode {
+                let selected = self.selected.contains(path);
+                widget::button::custom(cell)
+                    .class(if selected {
+                        style::Button::Suggested
+                    } else {
+                        style::Button::Text
+                    })
+                    .on_press(SvgerMessage::ToggleSelected(path.clone()))
+            } else {
+                widget::button::custom(cell)
+                    .class(style::Button::Text)
+                    .on_press(SvgerMessage::ShowDetails(path.clone()))
+            };
+
+            let context_menu = menu::items(
+                &HashMap::new(),
+                vec![
+                    menu::Item::Button(fl!("open-externally"), FileAction::OpenExternally(path.clone())),
+                    menu::Item::Button(fl!("copy-path"), FileAction::CopyPath(path.clone())),
+                    menu::Item::Divider,
+                    menu::Item::Button(fl!("delete"), FileAction::DeleteFile(path.clone())),
+                ],
             );
 
+            svg_grid = svg_grid.push(widget::context_menu(cell, Some(context_menu)));
+
             row_count += 1;
 
             if row_count == grid_rows_count {
@@ -183,7 +507,7 @@ impl Application for Svger {
             }
         }
 
-        widget::container(widget::scrollable(
+        let grid = widget::container(widget::scrollable(
             svg_grid
                 .column_alignment(Alignment::Center)
                 .row_alignment(Alignment::Center)
@@ -191,8 +515,24 @@ impl Application for Svger {
                 .column_spacing(8)
                 .width(Length::Fill),
         ))
-        .width(Length::Fill)
-        .into()
+        .width(Length::Fill);
+
+        let mut content = widget::column();
+        if let Some((done, total)) = self.export_progress {
+            content = content.push(widget::text::body(fl!(
+                "export-progress",
+                done = done,
+                total = total
+            )));
+        } else if let Some((ok, total)) = self.export_result {
+            content = content.push(widget::text::body(fl!(
+                "export-result",
+                ok = ok,
+                total = total
+            )));
+        }
+
+        content.push(grid).width(Length::Fill).into()
     }
 
     /// Application messages are handled here. The application state can be modified based on
@@ -214,11 +554,145 @@ impl Application for Svger {
                 }
 
                 // Set the title of the context drawer.
-                self.set_context_title(context_page.title());
+                self.set_context_title(self.context_page.title());
             }
             SvgerMessage::UpdateGridRowsCount(grid_rows_count) => {
                 self.grid_rows_count = grid_rows_count;
             }
+            SvgerMessage::OpenFolder => {
+                return Command::perform(
+                    async move {
+                        file_chooser::open::Dialog::new()
+                            .title(fl!("open-folder"))
+                            .open_folder()
+                            .await
+                            .ok()
+                            .and_then(|response| response.url().to_file_path().ok())
+                    },
+                    |path| match path {
+                        Some(path) => Message::from(SvgerMessage::FolderSelected(path)),
+                        None => Message::None,
+                    },
+                );
+            }
+            SvgerMessage::SearchInput(query) => {
+                self.search_query = query;
+            }
+            SvgerMessage::OpenExternally(path) => {
+                let _result = open::that_detached(path);
+            }
+            SvgerMessage::CopyPath(path) => {
+                return cosmic::iced::clipboard::write(path.to_string_lossy().into_owned());
+            }
+            SvgerMessage::DeleteFile(path) => {
+                if fs::remove_file(&path).is_ok() {
+                    self.svg_files.retain(|candidate| candidate != &path);
+                    self.selected.remove(&path);
+                    return self.update_grid_rows_count();
+                }
+            }
+            SvgerMessage::ShowDetails(path) => {
+                self.svg_details = Some(SvgDetails::from_path(&path));
+                self.context_page = ContextPage::Details(path);
+                self.core.window.show_context = true;
+                self.set_context_title(self.context_page.title());
+            }
+            SvgerMessage::SetDetailViewMode(mode) => {
+                self.detail_view_mode = mode;
+            }
+            SvgerMessage::SetThumbnailSize(size) => {
+                self.thumbnail_size = size.clamp(MIN_THUMBNAIL_SIZE, MAX_THUMBNAIL_SIZE);
+                return self.update_grid_rows_count();
+            }
+            SvgerMessage::ToggleSelectionMode => {
+                self.selection_mode = !self.selection_mode;
+                if !self.selection_mode {
+                    self.selected.clear();
+                }
+            }
+            SvgerMessage::ToggleSelected(path) => {
+                if !self.selected.remove(&path) {
+                    self.selected.insert(path);
+                }
+            }
+            SvgerMessage::SetExportResolution(index) => {
+                self.export_resolution = index;
+            }
+            SvgerMessage::ExportSelected => {
+                if self.selected.is_empty() {
+                    return Command::none();
+                }
+
+                return Command::perform(
+                    async move {
+                        file_chooser::open::Dialog::new()
+                            .title(fl!("export-png"))
+                            .open_folder()
+                            .await
+                            .ok()
+                            .and_then(|response| response.url().to_file_path().ok())
+                    },
+                    |path| match path {
+                        Some(path) => Message::from(SvgerMessage::ExportDestination(path)),
+                        None => Message::None,
+                    },
+                );
+            }
+            SvgerMessage::ExportDestination(dest) => {
+                let size = EXPORT_RESOLUTIONS[self.export_resolution];
+                let files: Vec<PathBuf> = self.selected.iter().cloned().collect();
+                let total = files.len();
+                self.export_progress = Some((0, total));
+                self.export_failures = 0;
+                self.export_result = None;
+
+                let commands = files.into_iter().map(|src| {
+                    let dest = dest.clone();
+                    Command::perform(
+                        async move { render_svg_to_png(&src, &dest, size) },
+                        move |result| {
+                            Message::from(SvgerMessage::ExportProgress {
+                                success: result.is_ok(),
+                                total,
+                            })
+                        },
+                    )
+                });
+
+                return Command::batch(commands);
+            }
+            SvgerMessage::ExportProgress { success, total } => {
+                let completed = self.export_progress.map_or(0, |(done, _)| done) + 1;
+                if !success {
+                    self.export_failures += 1;
+                }
+                self.export_progress = Some((completed, total));
+                if completed >= total {
+                    return self.update(SvgerMessage::ExportDone);
+                }
+            }
+            SvgerMessage::ExportDone => {
+                let (done, total) = self.export_progress.unwrap_or((0, 0));
+                self.export_result = Some((done - self.export_failures, total));
+                self.export_progress = None;
+                self.selection_mode = false;
+                self.selected.clear();
+            }
+            SvgerMessage::FilesDropped(paths) => {
+                for path in paths {
+                    if path.is_dir() {
+                        self.svg_files.extend(list_svg_files(&path));
+                    } else if path.extension().map_or(false, |ext| ext == "svg") {
+                        self.svg_files.push(path);
+                    }
+                }
+                return self.update_grid_rows_count();
+            }
+            SvgerMessage::FolderSelected(dir) => {
+                self.svg_files = list_svg_files(&dir);
+                self.populate_nav(&dir);
+                return self.update_grid_rows_count();
+            }
         }
         Command::none()
     }
@@ -229,21 +703,27 @@ impl Application for Svger {
             return None;
         }
 
-        Some(match self.context_page {
+        Some(match &self.context_page {
             ContextPage::About => self.about(),
+            ContextPage::Details(path) => self.details(path),
         })
     }
 
     fn subscription(&self) -> cosmic::iced::Subscription<Self::Message> {
-        event::listen_with(|message, _| match message {
+        let thumbnail_size = self.thumbnail_size;
+
+        event::listen_with(move |message, _| match message {
             Event::Window(window_id, window_event) => {
                 if window_id == window::Id::MAIN {
                     match window_event {
                         iced::window::Event::Resized { width, height: _ } => {
                             Some(SvgerMessage::UpdateGridRowsCount(Some(
-                                width as usize / GRID_ITEM_WIDTH,
+                                width as usize / thumbnail_size,
                             )))
                         }
+                        iced::window::Event::FileDropped(path) => {
+                            Some(SvgerMessage::FilesDropped(vec![path]))
+                        }
                         _ => None,
                     }
                 } else {
@@ -280,6 +760,133 @@ impl Svger {
             .into()
     }
 
+    /// The files to display, filtered case-insensitively by the search query on the file stem.
+    pub fn filtered_files(&self) -> Vec<&PathBuf> {
+        if self.search_query.is_empty() {
+            return self.svg_files.iter().collect();
+        }
+
+        let needle = self.search_query.to_lowercase();
+
+        self.svg_files
+            .iter()
+            .filter(|path| {
+                path.file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .map_or(false, |stem| stem.to_lowercase().contains(&needle))
+            })
+            .collect()
+    }
+
+    /// Rebuild the navigation sidebar from the subdirectories of `root`.
+    pub fn populate_nav(&mut self, root: &Path) {
+        self.nav = nav_bar::Model::default();
+
+        let root_name = root
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(".")
+            .to_string();
+
+        self.nav
+            .insert()
+            .text(root_name)
+            .data::<PathBuf>(root.to_path_buf())
+            .activate();
+
+        for dir in list_subdirs(root) {
+            let label = dir
+                .strip_prefix(root)
+                .unwrap_or(&dir)
+                .to_string_lossy()
+                .into_owned();
+
+            self.nav.insert().text(label).data::<PathBuf>(dir);
+        }
+    }
+
+    /// The detail page for a selected SVG: rendered/raw preview plus parsed metadata.
+    pub fn details(&self, path: &Path) -> Element<SvgerMessage> {
+        let cosmic_theme::Spacing {
+            space_xxs, space_s, ..
+        } = theme::active().cosmic().spacing;
+
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        let mode = self.detail_view_mode;
+        // A simple two-button toggle between rendered and raw views.
+        let view_toggle = widget::row()
+            .push(
+                widget::button::text(fl!("rendered"))
+                    .class(if mode == DetailViewMode::Rendered {
+                        style::Button::Suggested
+                    } else {
+                        style::Button::Standard
+                    })
+                    .on_press(SvgerMessage::SetDetailViewMode(DetailViewMode::Rendered)),
+            )
+            .push(
+                widget::button::text(fl!("raw"))
+                    .class(if mode == DetailViewMode::Raw {
+                        style::Button::Suggested
+                    } else {
+                        style::Button::Standard
+                    })
+                    .on_press(SvgerMessage::SetDetailViewMode(DetailViewMode::Raw)),
+            )
+            .spacing(space_xxs);
+
+        let details = self.svg_details.clone().unwrap_or_default();
+
+        let preview: Element<SvgerMessage> = match mode {
+            DetailViewMode::Rendered => widget::svg(widget::svg::Handle::from_path(path))
+                .width(256)
+                .height(256)
+                .into(),
+            DetailViewMode::Raw => widget::scrollable(
+                widget::text::monotext(details.source.clone()).width(Length::Fill),
+            )
+            .height(256)
+            .into(),
+        };
+
+        let metadata = widget::column()
+            .push(widget::text::heading(fl!("metadata")))
+            .push(widget::text::body(format!(
+                "{}: {}",
+                fl!("width"),
+                details.width.clone().unwrap_or_default()
+            )))
+            .push(widget::text::body(format!(
+                "{}: {}",
+                fl!("height"),
+                details.height.clone().unwrap_or_default()
+            )))
+            .push(widget::text::body(format!(
+                "viewBox: {}",
+                details.view_box.clone().unwrap_or_default()
+            )))
+            .push(widget::text::body(format!(
+                "{}: {}",
+                fl!("path-count"),
+                details.path_count
+            )))
+            .push(widget::text::body(format!("{}: {}", fl!("g-count"), details.g_count)))
+            .spacing(space_xxs);
+
+        widget::column()
+            .push(widget::text::title4(name))
+            .push(view_toggle)
+            .push(preview)
+            .push(metadata)
+            .spacing(space_s)
+            .align_items(Alignment::Center)
+            .into()
+    }
+
     /// Updates the header and window titles.
     pub fn update_titles(&mut self) -> Command<SvgerMessage> {
         let window_title = fl!("app-title");
@@ -288,8 +895,10 @@ impl Svger {
     }
 
     pub fn update_grid_rows_count(&mut self) -> Command<SvgerMessage> {
+        let thumbnail_size = self.thumbnail_size;
+
         window::fetch_size(window::Id::MAIN, move |size| {
-            let grid_rows_count = size.width as usize / GRID_ITEM_WIDTH;
+            let grid_rows_count = size.width as usize / thumbnail_size;
 
             grid_rows_count
         })